@@ -3,58 +3,382 @@
 #![deny(missing_docs)]
 #![cfg_attr(feature = "specialization", feature(specialization))]
 
+use std::cmp::Ordering;
 use std::fmt::{self, Debug};
 use std::iter::FromIterator;
 use std::mem::{replace, swap};
+use std::ops::{Deref, DerefMut};
+use std::slice;
+use std::vec;
+
+/// A type that compares two values to determine their heap ordering.
+///
+/// This is used in place of `Ord` so that `SkewHeap` can support comparisons other than the
+/// item's natural ordering, such as a min-heap ordering or a key projection.
+pub trait Compare<T: ?Sized> {
+    /// Compares two values, returning `Ordering::Greater` if `a` should be closer to the top of
+    /// the heap than `b`.
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+/// A comparator that orders a heap as a max-heap, using `T`'s natural ordering.
+///
+/// This is the default comparator for `SkewHeap`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MaxComparator;
+
+impl<T: Ord> Compare<T> for MaxComparator {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// A comparator that orders a heap as a min-heap, using the reverse of `T`'s natural ordering.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MinComparator;
+
+impl<T: Ord> Compare<T> for MinComparator {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+/// A comparator that orders a heap using a function.
+#[derive(Clone, Copy, Debug)]
+pub struct FnComparator<F>(F);
+
+impl<T, F: Fn(&T, &T) -> Ordering> Compare<T> for FnComparator<F> {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        (self.0)(a, b)
+    }
+}
+
+/// A comparator that orders a heap by a key projected from each item.
+#[derive(Clone, Copy, Debug)]
+pub struct KeyComparator<F>(F);
+
+impl<T, K: Ord, F: Fn(&T) -> K> Compare<T> for KeyComparator<F> {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        (self.0)(a).cmp(&(self.0)(b))
+    }
+}
+
+/// An opaque handle to an item previously pushed onto a `SkewHeap`.
+///
+/// A handle is returned by [`push`] and can later be passed to [`remove`] or [`change_key`] to
+/// operate on that specific item, even after other items have been pushed or popped. A handle
+/// must only be used with the `SkewHeap` that produced it, and only while the item it refers to
+/// is still present in that heap. Using it otherwise panics if its slot is vacant; if the slot
+/// has since been reused by another item, or the handle came from a different heap, the
+/// operation silently applies to whatever item occupies that slot.
+///
+/// [`push`]: struct.SkewHeap.html#method.push
+/// [`remove`]: struct.SkewHeap.html#method.remove
+/// [`change_key`]: struct.SkewHeap.html#method.change_key
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Handle(u32);
+
+enum NodeSlot<T> {
+    Empty { next: Option<u32> },
+    Full { left: Option<u32>, right: Option<u32>, parent: Option<u32>, item: T },
+}
 
-struct Node<T> {
-    l: Option<Box<Node<T>>>,
-    r: Option<Box<Node<T>>>,
-    item: T,
+/// A `Vec`-backed store of heap nodes, addressed by index so that a `Handle` remains valid
+/// across pushes and pops of other items.
+struct Arena<T> {
+    slots: Vec<NodeSlot<T>>,
+    free: Option<u32>,
+}
+
+impl<T> Arena<T> {
+    fn new() -> Self {
+        Arena { slots: Vec::new(), free: None }
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Arena { slots: Vec::with_capacity(capacity), free: None }
+    }
+
+    fn alloc(&mut self, item: T) -> u32 {
+        let slot = NodeSlot::Full { left: None, right: None, parent: None, item: item };
+
+        match self.free {
+            Some(idx) => {
+                self.free = match self.slots[idx as usize] {
+                    NodeSlot::Empty { next } => next,
+                    NodeSlot::Full { .. } => unreachable!("corrupt free list"),
+                };
+
+                self.slots[idx as usize] = slot;
+                idx
+            }
+            None => {
+                self.slots.push(slot);
+                (self.slots.len() - 1) as u32
+            }
+        }
+    }
+
+    fn dealloc(&mut self, idx: u32) -> T {
+        match replace(&mut self.slots[idx as usize], NodeSlot::Empty { next: self.free }) {
+            NodeSlot::Full { item, .. } => {
+                self.free = Some(idx);
+                item
+            }
+            NodeSlot::Empty { .. } => panic!("invalid handle"),
+        }
+    }
+
+    fn slot(&self, idx: u32) -> &NodeSlot<T> {
+        match self.slots.get(idx as usize) {
+            Some(slot @ NodeSlot::Full { .. }) => slot,
+            _ => panic!("invalid handle"),
+        }
+    }
+
+    fn slot_mut(&mut self, idx: u32) -> &mut NodeSlot<T> {
+        match self.slots.get_mut(idx as usize) {
+            Some(slot @ NodeSlot::Full { .. }) => slot,
+            _ => panic!("invalid handle"),
+        }
+    }
+
+    fn left(&self, idx: u32) -> Option<u32> {
+        match *self.slot(idx) {
+            NodeSlot::Full { left, .. } => left,
+            NodeSlot::Empty { .. } => unreachable!(),
+        }
+    }
+
+    fn right(&self, idx: u32) -> Option<u32> {
+        match *self.slot(idx) {
+            NodeSlot::Full { right, .. } => right,
+            NodeSlot::Empty { .. } => unreachable!(),
+        }
+    }
+
+    fn parent(&self, idx: u32) -> Option<u32> {
+        match *self.slot(idx) {
+            NodeSlot::Full { parent, .. } => parent,
+            NodeSlot::Empty { .. } => unreachable!(),
+        }
+    }
+
+    fn item(&self, idx: u32) -> &T {
+        match self.slot(idx) {
+            NodeSlot::Full { item, .. } => item,
+            NodeSlot::Empty { .. } => unreachable!(),
+        }
+    }
+
+    fn item_mut(&mut self, idx: u32) -> &mut T {
+        match self.slot_mut(idx) {
+            NodeSlot::Full { item, .. } => item,
+            NodeSlot::Empty { .. } => unreachable!(),
+        }
+    }
+
+    fn set_left(&mut self, idx: u32, left: Option<u32>) {
+        match self.slot_mut(idx) {
+            NodeSlot::Full { left: l, .. } => *l = left,
+            NodeSlot::Empty { .. } => unreachable!(),
+        }
+    }
+
+    fn set_right(&mut self, idx: u32, right: Option<u32>) {
+        match self.slot_mut(idx) {
+            NodeSlot::Full { right: r, .. } => *r = right,
+            NodeSlot::Empty { .. } => unreachable!(),
+        }
+    }
+
+    fn set_parent(&mut self, idx: u32, parent: Option<u32>) {
+        match self.slot_mut(idx) {
+            NodeSlot::Full { parent: p, .. } => *p = parent,
+            NodeSlot::Empty { .. } => unreachable!(),
+        }
+    }
+
+    /// Sets `idx`'s left child, fixing up the child's parent pointer to match.
+    fn attach_left(&mut self, idx: u32, child: Option<u32>) {
+        self.set_left(idx, child);
+
+        if let Some(child) = child {
+            self.set_parent(child, Some(idx));
+        }
+    }
+
+    /// Sets `idx`'s right child, fixing up the child's parent pointer to match.
+    fn attach_right(&mut self, idx: u32, child: Option<u32>) {
+        self.set_right(idx, child);
+
+        if let Some(child) = child {
+            self.set_parent(child, Some(idx));
+        }
+    }
 }
 
-/// Merges two possibly empty heaps into a single heap.
-fn merge<T: Ord>(mut a: &mut Option<Box<Node<T>>>, mut b: Option<Box<Node<T>>>) {
+/// Merges two possibly empty heaps into a single heap, returning the index of its root.
+fn merge<T, C: Compare<T>>(arena: &mut Arena<T>, cmp: &C, a: Option<u32>, b: Option<u32>) -> Option<u32> {
+    let (mut winner, mut loser) = match (a, b) {
+        (None, None) => return None,
+        (Some(a), None) => {
+            arena.set_parent(a, None);
+            return Some(a);
+        }
+        (None, Some(b)) => {
+            arena.set_parent(b, None);
+            return Some(b);
+        }
+        (Some(a), Some(b)) => {
+            if cmp.compare(arena.item(a), arena.item(b)) == Ordering::Less {
+                (b, a)
+            } else {
+                (a, b)
+            }
+        }
+    };
+
+    let root = winner;
+    arena.set_parent(root, None);
+
     loop {
-        a = {
-            let a = a;
-
-            match *a {
-                None => return *a = b,
-                Some(ref mut a) => match b {
-                    None => return,
-                    Some(mut bn) => {
-                        if a.item < bn.item {
-                            swap(a, &mut bn);
-                        }
-
-                        let a = &mut **a;
-                        swap(&mut a.l, &mut a.r);
-
-                        b = replace(&mut a.l, Some(bn));
-                        &mut a.l
-                    }
-                }
+        let left = arena.left(winner);
+        let right = arena.right(winner);
+        arena.attach_right(winner, left);
+
+        match right {
+            None => {
+                arena.attach_left(winner, Some(loser));
+                break;
+            }
+            Some(a) => {
+                let b = loser;
+
+                let (new_winner, new_loser) = if cmp.compare(arena.item(a), arena.item(b)) == Ordering::Less {
+                    (b, a)
+                } else {
+                    (a, b)
+                };
+
+                arena.attach_left(winner, Some(new_winner));
+                winner = new_winner;
+                loser = new_loser;
             }
-        };
+        }
     }
+
+    Some(root)
 }
 
 /// A skew heap.
-pub struct SkewHeap<T: Ord> {
-    nodes: Nodes<T>,
+///
+/// `SkewHeap<T>` is a max-heap by default. To build a min-heap, or a heap ordered by some other
+/// criterion, supply a comparator `C` that implements `Compare<T>`; see [`new_min`], [`new_by`],
+/// and [`new_by_key`].
+///
+/// [`new_min`]: #method.new_min
+/// [`new_by`]: #method.new_by
+/// [`new_by_key`]: #method.new_by_key
+pub struct SkewHeap<T, C = MaxComparator> {
+    arena: Arena<T>,
+    root: Option<u32>,
     len: usize,
+    cmp: C,
 }
 
 impl<T: Ord> SkewHeap<T> {
-    /// Returns an empty heap.
+    /// Returns an empty max-heap, ordered by `T`'s natural ordering.
     pub fn new() -> Self {
-        SkewHeap { nodes: Nodes { node: None } , len: 0 }
+        Self::new_by(MaxComparator)
     }
 
+    /// Returns an empty max-heap with space pre-allocated for at least `capacity` items.
+    ///
+    /// Pre-allocating lets a workload of repeated pushes and pops reuse the same slots instead
+    /// of growing the underlying storage as it goes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_by(capacity, MaxComparator)
+    }
+}
+
+impl<T: Ord> SkewHeap<T, MinComparator> {
+    /// Returns an empty min-heap, ordered by the reverse of `T`'s natural ordering.
+    pub fn new_min() -> Self {
+        Self::new_by(MinComparator)
+    }
+}
+
+impl<T, C: Compare<T>> SkewHeap<T, C> {
+    /// Returns an empty heap that orders its items using the given comparator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skew_heap::{Compare, SkewHeap};
+    /// use std::cmp::Ordering;
+    ///
+    /// struct ByAbs;
+    ///
+    /// impl Compare<i32> for ByAbs {
+    ///     fn compare(&self, a: &i32, b: &i32) -> Ordering {
+    ///         a.abs().cmp(&b.abs())
+    ///     }
+    /// }
+    ///
+    /// let mut h = SkewHeap::new_by(ByAbs);
+    /// h.extend(vec![1, -3, 2]);
+    /// assert_eq!(h.peek(), Some(&-3));
+    /// ```
+    pub fn new_by(cmp: C) -> Self {
+        SkewHeap { arena: Arena::new(), root: None, len: 0, cmp: cmp }
+    }
+
+    /// Returns an empty heap, ordered by the given comparator, with space pre-allocated for at
+    /// least `capacity` items.
+    pub fn with_capacity_by(capacity: usize, cmp: C) -> Self {
+        SkewHeap { arena: Arena::with_capacity(capacity), root: None, len: 0, cmp: cmp }
+    }
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering> SkewHeap<T, FnComparator<F>> {
+    /// Returns an empty heap that orders its items using the given comparison function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skew_heap::SkewHeap;
+    ///
+    /// let mut h = SkewHeap::new_by_fn(|a: &i32, b: &i32| b.cmp(a));
+    /// h.extend(vec![1, 2, 3]);
+    /// assert_eq!(h.peek(), Some(&1));
+    /// ```
+    pub fn new_by_fn(f: F) -> Self {
+        Self::new_by(FnComparator(f))
+    }
+}
+
+impl<T, K: Ord, F: Fn(&T) -> K> SkewHeap<T, KeyComparator<F>> {
+    /// Returns an empty heap that orders its items by the key returned by the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skew_heap::SkewHeap;
+    ///
+    /// let mut h = SkewHeap::new_by_key(|n: &i32| n.abs());
+    /// h.extend(vec![1, -3, 2]);
+    /// assert_eq!(h.peek(), Some(&-3));
+    /// ```
+    pub fn new_by_key(f: F) -> Self {
+        Self::new_by(KeyComparator(f))
+    }
+}
+
+impl<T, C: Compare<T>> SkewHeap<T, C> {
     /// Returns `true` if the heap contains no items.
     pub fn is_empty(&self) -> bool {
-        self.nodes.node.is_none()
+        self.root.is_none()
     }
 
     /// Returns the number of items in the heap.
@@ -62,24 +386,129 @@ impl<T: Ord> SkewHeap<T> {
         self.len
     }
 
+    /// Shrinks the capacity of the heap's backing storage as much as possible.
+    ///
+    /// Only spare capacity is released: slots vacated by removed items are kept, and later
+    /// pushes still reuse them before growing the underlying storage again.
+    pub fn shrink_to_fit(&mut self) {
+        self.arena.slots.shrink_to_fit();
+    }
+
     /// Returns an iterator that yields references to the heap's items in arbitrary order.
     pub fn iter(&self) -> Iter<T> {
-        Iter {
-            nodes: self.nodes.node.as_ref().map_or(vec![], |node| vec![node]),
-            len: self.len,
-        }
+        Iter { slots: self.arena.slots.iter(), len: self.len }
     }
 
-    /// Returns a reference to the heap's greatest item.
+    /// Returns a reference to the heap's greatest item (with respect to its comparator).
     ///
     /// Returns `None` if the heap is empty.
     pub fn peek(&self) -> Option<&T> {
-        self.nodes.node.as_ref().map(|node| &node.item)
+        self.root.map(|idx| self.arena.item(idx))
     }
 
-    /// Pushes the given item onto the heap.
-    pub fn push(&mut self, item: T) {
-        self.push_node(Box::new(Node { l: None, r: None, item: item }));
+    /// Returns a guard that derefs to the heap's greatest item, allowing it to be mutated in
+    /// place.
+    ///
+    /// Returns `None` if the heap is empty.
+    ///
+    /// The heap is re-ordered as necessary when the guard is dropped, so unlike `peek`, this
+    /// allows callers to adjust the top item's priority without a `pop` and `push` round trip.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skew_heap::SkewHeap;
+    ///
+    /// let mut h = SkewHeap::new();
+    /// h.extend(vec![1, 5, 2]);
+    ///
+    /// {
+    ///     let mut top = h.peek_mut().unwrap();
+    ///     *top = 0;
+    /// }
+    ///
+    /// assert_eq!(h.peek(), Some(&2));
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T, C>> {
+        if self.root.is_none() {
+            None
+        } else {
+            Some(PeekMut { heap: self, mutated: false })
+        }
+    }
+
+    /// Pushes the given item onto the heap, returning a handle that can later be used with
+    /// [`remove`] or [`change_key`].
+    ///
+    /// [`remove`]: #method.remove
+    /// [`change_key`]: #method.change_key
+    pub fn push(&mut self, item: T) -> Handle {
+        let idx = self.arena.alloc(item);
+        self.push_node(idx);
+        Handle(idx)
+    }
+
+    /// Removes the item referred to by the given handle and returns it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skew_heap::SkewHeap;
+    ///
+    /// let mut h = SkewHeap::new();
+    /// h.push(1);
+    /// let h2 = h.push(2);
+    /// h.push(3);
+    ///
+    /// assert_eq!(h.remove(h2), 2);
+    /// assert_eq!(h.len(), 2);
+    ///
+    /// let mut items: Vec<_> = h.into_iter().collect();
+    /// items.sort();
+    /// assert_eq!(items, [1, 3]);
+    /// ```
+    pub fn remove(&mut self, handle: Handle) -> T {
+        let idx = handle.0;
+
+        self.detach_from_parent(idx);
+        let (left, right) = self.detach_children(idx);
+        let merged = merge(&mut self.arena, &self.cmp, left, right);
+        self.root = merge(&mut self.arena, &self.cmp, self.root, merged);
+
+        self.len -= 1;
+        self.arena.dealloc(idx)
+    }
+
+    /// Replaces the item referred to by the given handle, re-ordering the heap as necessary, and
+    /// returns the item's previous value.
+    ///
+    /// This works for both increases and decreases in priority.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skew_heap::SkewHeap;
+    ///
+    /// let mut h = SkewHeap::new();
+    /// h.push(1);
+    /// let h2 = h.push(2);
+    /// h.push(3);
+    ///
+    /// assert_eq!(h.change_key(h2, 10), 2);
+    /// assert_eq!(h.peek(), Some(&10));
+    /// ```
+    pub fn change_key(&mut self, handle: Handle, mut item: T) -> T {
+        let idx = handle.0;
+
+        self.detach_from_parent(idx);
+        let (left, right) = self.detach_children(idx);
+        let merged = merge(&mut self.arena, &self.cmp, left, right);
+        self.root = merge(&mut self.arena, &self.cmp, self.root, merged);
+
+        swap(self.arena.item_mut(idx), &mut item);
+        self.root = merge(&mut self.arena, &self.cmp, self.root, Some(idx));
+
+        item
     }
 
     /// Moves all items from the given heap into the heap.
@@ -108,19 +537,54 @@ impl<T: Ord> SkewHeap<T> {
     /// ```
     pub fn append(&mut self, other: &mut Self) {
         self.len += replace(&mut other.len, 0);
-        merge(&mut self.nodes.node, other.nodes.node.take());
+
+        let other_slots = replace(&mut other.arena.slots, Vec::new());
+        other.arena.free = None;
+        let other_root = other.root.take();
+
+        // Nodes moved from `other`'s arena into `self`'s arena get new indices, so every
+        // pointer between them must be translated through this table.
+        let mut new_index = vec![None; other_slots.len()];
+        let mut children = vec![None; other_slots.len()];
+        let mut other_slots = other_slots;
+
+        for old_idx in 0..other_slots.len() {
+            if let NodeSlot::Full { .. } = other_slots[old_idx] {
+                let slot = replace(&mut other_slots[old_idx], NodeSlot::Empty { next: None });
+
+                if let NodeSlot::Full { item, left, right, .. } = slot {
+                    new_index[old_idx] = Some(self.arena.alloc(item));
+                    children[old_idx] = Some((left, right));
+                }
+            }
+        }
+
+        for old_idx in 0..other_slots.len() {
+            if let (Some(idx), Some((left, right))) = (new_index[old_idx], children[old_idx]) {
+                let left = left.and_then(|old| new_index[old as usize]);
+                let right = right.and_then(|old| new_index[old as usize]);
+                self.arena.attach_left(idx, left);
+                self.arena.attach_right(idx, right);
+            }
+        }
+
+        let other_root = other_root.and_then(|old| new_index[old as usize]);
+        self.root = merge(&mut self.arena, &self.cmp, self.root, other_root);
     }
 
     /// Removes all items from the heap.
     pub fn clear(&mut self) {
-        *self = Self::new();
+        self.arena.slots.clear();
+        self.arena.free = None;
+        self.root = None;
+        self.len = 0;
     }
 
     /// Removes the heap's greatest item and returns it.
     ///
     /// Returns `None` if the heap was empty.
     pub fn pop(&mut self) -> Option<T> {
-        self.pop_node().map(|node| node.item)
+        self.pop_node()
     }
 
     /// Pushes the given item onto to the heap, then removes the heap's greatest item and returns
@@ -154,12 +618,13 @@ impl<T: Ord> SkewHeap<T> {
     /// assert_eq!(h.pop(), None);
     /// ```
     pub fn push_pop(&mut self, mut item: T) -> T {
-        match self.nodes.node {
-            Some(ref root) if item >= root.item => {}
-            _ => if let Some(mut node) = self.pop_node() {
-                swap(&mut node.item, &mut item);
-                self.push_node(node);
-            },
+        match self.root {
+            Some(idx) if self.cmp.compare(&item, self.arena.item(idx)) != Ordering::Less => {}
+            Some(idx) => {
+                swap(self.arena.item_mut(idx), &mut item);
+                self.resift_root();
+            }
+            None => {}
         }
 
         item
@@ -192,39 +657,188 @@ impl<T: Ord> SkewHeap<T> {
     /// assert_eq!(h.pop(), None);
     /// ```
     pub fn replace(&mut self, mut item: T) -> Option<T> {
-        match self.nodes.node {
-            Some(ref mut root) if item >= root.item => Some(replace(&mut root.item, item)),
-            _ => match self.pop_node() {
-                None => {
-                    self.push(item);
-                    None
-                }
-                Some(mut node) => {
-                    swap(&mut node.item, &mut item);
-                    self.push_node(node);
-                    Some(item)
+        match self.root {
+            Some(idx) if self.cmp.compare(&item, self.arena.item(idx)) != Ordering::Less => {
+                Some(replace(self.arena.item_mut(idx), item))
+            }
+            Some(idx) => {
+                swap(self.arena.item_mut(idx), &mut item);
+                self.resift_root();
+                Some(item)
+            }
+            None => {
+                self.push(item);
+                None
+            }
+        }
+    }
+
+    /// Consumes the heap and returns a `Vec` containing its items, sorted from least to
+    /// greatest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skew_heap::SkewHeap;
+    ///
+    /// let mut h = SkewHeap::new();
+    /// h.extend(vec![3, 1, 2]);
+    /// assert_eq!(h.into_sorted_vec(), [1, 2, 3]);
+    /// ```
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len());
+
+        while let Some(item) = self.pop_node() {
+            vec.push(item);
+        }
+
+        vec.reverse();
+        vec
+    }
+
+    /// Returns an iterator that removes the heap's items in order from greatest to least.
+    ///
+    /// Unlike [`into_iter`], this yields items in priority order rather than arbitrary order.
+    /// If the iterator is dropped before being fully consumed, the remaining items are removed
+    /// from the heap, which is left empty either way.
+    ///
+    /// [`into_iter`]: #method.into_iter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skew_heap::SkewHeap;
+    ///
+    /// let mut h = SkewHeap::new();
+    /// h.extend(vec![1, 3, 2]);
+    /// assert_eq!(h.drain_sorted().collect::<Vec<_>>(), [3, 2, 1]);
+    /// assert!(h.is_empty());
+    /// ```
+    pub fn drain_sorted(&mut self) -> DrainSorted<'_, T, C> {
+        DrainSorted { heap: self }
+    }
+}
+
+impl<T, C: Compare<T>> SkewHeap<T, C> {
+    fn push_node(&mut self, idx: u32) {
+        self.len += 1;
+        self.root = merge(&mut self.arena, &self.cmp, self.root, Some(idx));
+    }
+
+    fn pop_node(&mut self) -> Option<T> {
+        let idx = self.root.take()?;
+        let (left, right) = self.detach_children(idx);
+        self.root = merge(&mut self.arena, &self.cmp, left, right);
+        self.len -= 1;
+        Some(self.arena.dealloc(idx))
+    }
+
+    /// Detaches `idx`'s children from it, returning them.
+    fn detach_children(&mut self, idx: u32) -> (Option<u32>, Option<u32>) {
+        let left = self.arena.left(idx);
+        let right = self.arena.right(idx);
+        self.arena.set_left(idx, None);
+        self.arena.set_right(idx, None);
+        (left, right)
+    }
+
+    /// Detaches `idx` from its parent, or clears `self.root` if `idx` is the root.
+    fn detach_from_parent(&mut self, idx: u32) {
+        match self.arena.parent(idx) {
+            Some(parent) => {
+                if self.arena.left(parent) == Some(idx) {
+                    self.arena.set_left(parent, None);
+                } else {
+                    self.arena.set_right(parent, None);
                 }
-            },
+            }
+            None => self.root = None,
+        }
+    }
+
+    /// Re-establishes the heap property after the root's item has been mutated in place.
+    fn resift_root(&mut self) {
+        if let Some(idx) = self.root {
+            let (left, right) = self.detach_children(idx);
+            self.root = merge(&mut self.arena, &self.cmp, left, right);
+            self.root = merge(&mut self.arena, &self.cmp, self.root, Some(idx));
         }
     }
 }
 
-impl<T: Ord> SkewHeap<T> {
-    fn push_node(&mut self, node: Box<Node<T>>) {
-        debug_assert!(node.l.is_none());
-        debug_assert!(node.r.is_none());
+/// A guard that allows in-place mutation of a `SkewHeap`'s greatest item.
+///
+/// Returned by [`SkewHeap::peek_mut`]. If the guard is mutably dereferenced, the heap is
+/// re-ordered when the guard is dropped so that it is restored to a valid state.
+///
+/// [`SkewHeap::peek_mut`]: struct.SkewHeap.html#method.peek_mut
+pub struct PeekMut<'a, T: 'a, C: 'a + Compare<T>> {
+    heap: &'a mut SkewHeap<T, C>,
+    mutated: bool,
+}
 
-        self.len += 1;
-        merge(&mut self.nodes.node, Some(node));
+impl<'a, T, C: Compare<T>> Drop for PeekMut<'a, T, C> {
+    fn drop(&mut self) {
+        if self.mutated {
+            self.heap.resift_root();
+        }
+    }
+}
+
+impl<'a, T, C: Compare<T>> Deref for PeekMut<'a, T, C> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.heap.arena.item(self.heap.root.unwrap())
+    }
+}
+
+impl<'a, T, C: Compare<T>> DerefMut for PeekMut<'a, T, C> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.mutated = true;
+        let idx = self.heap.root.unwrap();
+        self.heap.arena.item_mut(idx)
+    }
+}
+
+/// An iterator that removes a `SkewHeap`'s items in order from greatest to least.
+///
+/// Returned by [`SkewHeap::drain_sorted`]. If dropped before being fully consumed, the
+/// remaining items are removed from the heap.
+///
+/// [`SkewHeap::drain_sorted`]: struct.SkewHeap.html#method.drain_sorted
+pub struct DrainSorted<'a, T: 'a, C: 'a + Compare<T>> {
+    heap: &'a mut SkewHeap<T, C>,
+}
+
+impl<'a, T, C: Compare<T>> Debug for DrainSorted<'a, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DrainSorted").field("len", &self.heap.len()).finish()
+    }
+}
+
+impl<'a, T, C: Compare<T>> Drop for DrainSorted<'a, T, C> {
+    fn drop(&mut self) {
+        while self.heap.pop_node().is_some() {}
     }
+}
+
+impl<'a, T, C: Compare<T>> Iterator for DrainSorted<'a, T, C> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.heap.pop_node()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.heap.len();
+        (len, Some(len))
+    }
+}
 
-    fn pop_node(&mut self) -> Option<Box<Node<T>>> {
-        self.nodes.node.take().map(|mut node| {
-            self.len -= 1;
-            self.nodes.node = node.l.take();
-            merge(&mut self.nodes.node, node.r.take());
-            node
-        })
+impl<'a, T, C: Compare<T>> ExactSizeIterator for DrainSorted<'a, T, C> {
+    fn len(&self) -> usize {
+        self.heap.len()
     }
 }
 
@@ -234,33 +848,27 @@ impl<T: Ord> Default for SkewHeap<T> {
     }
 }
 
-impl<T: Ord + Clone> Clone for SkewHeap<T> {
+impl<T: Clone, C: Compare<T> + Clone> Clone for SkewHeap<T, C> {
     fn clone(&self) -> Self {
-        self.iter().cloned().collect()
+        let mut heap = Self::new_by(self.cmp.clone());
+        heap.extend(self.iter().cloned());
+        heap
     }
 
     fn clone_from(&mut self, other: &Self) {
-        let nodes = replace(self, Self::new()).nodes;
-        let mut other = other.iter();
-
-        for (mut node, item) in nodes.zip(&mut other) {
-            node.item.clone_from(item);
-            self.push_node(node);
-        }
-
-        for item in other {
-            self.push(item.clone());
-        }
+        self.clear();
+        self.cmp = other.cmp.clone();
+        self.extend(other.iter().cloned());
     }
 }
 
-impl<T: Ord> Extend<T> for SkewHeap<T> {
+impl<T, C: Compare<T>> Extend<T> for SkewHeap<T, C> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
         <Self as SpecExtend<I>>::spec_extend(self, items);
     }
 }
 
-impl<'a, T: 'a + Ord + Copy> Extend<&'a T> for SkewHeap<T> {
+impl<'a, T: 'a + Copy, C: Compare<T>> Extend<&'a T> for SkewHeap<T, C> {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, items: I) {
         for item in items {
             self.push(*item);
@@ -273,7 +881,7 @@ trait SpecExtend<I: IntoIterator> {
 }
 
 #[cfg(not(feature = "specialization"))]
-impl<I: IntoIterator> SpecExtend<I> for SkewHeap<I::Item> where I::Item: Ord {
+impl<I: IntoIterator, C: Compare<I::Item>> SpecExtend<I> for SkewHeap<I::Item, C> {
     fn spec_extend(&mut self, items: I) {
         for item in items {
             self.push(item);
@@ -284,7 +892,7 @@ impl<I: IntoIterator> SpecExtend<I> for SkewHeap<I::Item> where I::Item: Ord {
 #[cfg(feature = "specialization")]
 macro_rules! spec_extend {
     () => {
-        impl<I: IntoIterator> SpecExtend<I> for SkewHeap<I::Item> where I::Item: Ord {
+        impl<I: IntoIterator, C: Compare<I::Item>> SpecExtend<I> for SkewHeap<I::Item, C> {
             default fn spec_extend(&mut self, items: I) {
                 for item in items {
                     self.push(item);
@@ -292,8 +900,8 @@ macro_rules! spec_extend {
             }
         }
 
-        impl<T: Ord> SpecExtend<SkewHeap<T>> for SkewHeap<T> {
-            fn spec_extend(&mut self, ref mut other: SkewHeap<T>) {
+        impl<T, C: Compare<T>> SpecExtend<SkewHeap<T, C>> for SkewHeap<T, C> {
+            fn spec_extend(&mut self, ref mut other: SkewHeap<T, C>) {
                 self.append(other);
             }
         }
@@ -319,56 +927,24 @@ impl<'a, T: 'a + Ord + Copy> FromIterator<&'a T> for SkewHeap<T> {
     }
 }
 
-impl<T: Ord + Debug> Debug for SkewHeap<T> {
+impl<T: Debug, C: Compare<T>> Debug for SkewHeap<T, C> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_list().entries(self).finish()
     }
 }
 
-struct Nodes<T> {
-    node: Option<Box<Node<T>>>,
-}
-
-impl<T> Drop for Nodes<T> {
-    fn drop(&mut self) {
-        for _ in self {}
-    }
-}
-
-impl<T> Iterator for Nodes<T> {
-    type Item = Box<Node<T>>;
-
-    fn next(&mut self) -> Option<Box<Node<T>>> {
-        self.node.take().map(|mut node| {
-            loop {
-                match node.l.take() {
-                    None => {
-                        self.node = node.r.take();
-                        return node;
-                    }
-                    Some(mut l) => {
-                        node.l = l.r.take();
-                        l.r = Some(node);
-                        node = l;
-                    }
-                }
-            }
-        })
-    }
-}
-
-impl<T: Ord> IntoIterator for SkewHeap<T> {
+impl<T, C: Compare<T>> IntoIterator for SkewHeap<T, C> {
     type Item = T;
     type IntoIter = IntoIter<T>;
 
     fn into_iter(self) -> IntoIter<T> {
-        IntoIter { nodes: self.nodes, len: self.len }
+        IntoIter { slots: self.arena.slots.into_iter(), len: self.len }
     }
 }
 
 /// An iterator that yields a `SkewHeap`'s items in arbitrary order.
 pub struct IntoIter<T> {
-    nodes: Nodes<T>,
+    slots: vec::IntoIter<NodeSlot<T>>,
     len: usize,
 }
 
@@ -381,7 +957,7 @@ impl<T> Debug for IntoIter<T> {
 impl<T> Default for IntoIter<T> {
     fn default() -> Self {
         IntoIter {
-            nodes: Nodes { node: None },
+            slots: Vec::new().into_iter(),
             len: 0,
         }
     }
@@ -391,10 +967,14 @@ impl<T> Iterator for IntoIter<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<T> {
-        self.nodes.next().map(|node| {
-            self.len -= 1;
-            node.item
-        })
+        while let Some(slot) = self.slots.next() {
+            if let NodeSlot::Full { item, .. } = slot {
+                self.len -= 1;
+                return Some(item);
+            }
+        }
+
+        None
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -404,7 +984,14 @@ impl<T> Iterator for IntoIter<T> {
 
 impl<T> DoubleEndedIterator for IntoIter<T> {
     fn next_back(&mut self) -> Option<T> {
-        self.next()
+        while let Some(slot) = self.slots.next_back() {
+            if let NodeSlot::Full { item, .. } = slot {
+                self.len -= 1;
+                return Some(item);
+            }
+        }
+
+        None
     }
 }
 
@@ -414,7 +1001,7 @@ impl<T> ExactSizeIterator for IntoIter<T> {
     }
 }
 
-impl<'a, T: Ord> IntoIterator for &'a SkewHeap<T> {
+impl<'a, T, C: Compare<T>> IntoIterator for &'a SkewHeap<T, C> {
     type Item = &'a T;
     type IntoIter = Iter<'a, T>;
 
@@ -425,17 +1012,17 @@ impl<'a, T: Ord> IntoIterator for &'a SkewHeap<T> {
 
 /// An iterator that yields references to a `SkewHeap`'s items in arbitrary order.
 pub struct Iter<'a, T: 'a> {
-    nodes: Vec<&'a Node<T>>,
+    slots: slice::Iter<'a, NodeSlot<T>>,
     len: usize,
 }
 
 impl<'a, T> Clone for Iter<'a, T> {
     fn clone(&self) -> Self {
-        Iter { nodes: self.nodes.clone(), len: self.len }
+        Iter { slots: self.slots.clone(), len: self.len }
     }
 
     fn clone_from(&mut self, other: &Self) {
-        self.nodes.clone_from(&other.nodes);
+        self.slots = other.slots.clone();
         self.len = other.len;
     }
 }
@@ -449,7 +1036,7 @@ impl<'a, T> Debug for Iter<'a, T> {
 impl<'a, T> Default for Iter<'a, T> {
     fn default() -> Self {
         Iter {
-            nodes: vec![],
+            slots: <&[NodeSlot<T>]>::default().iter(),
             len: 0,
         }
     }
@@ -459,12 +1046,14 @@ impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<&'a T> {
-        self.nodes.pop().map(|node| {
-            self.len -= 1;
-            if let Some(ref l) = node.l { self.nodes.push(l); }
-            if let Some(ref r) = node.r { self.nodes.push(r); }
-            &node.item
-        })
+        while let Some(slot) = self.slots.next() {
+            if let NodeSlot::Full { item, .. } = slot {
+                self.len -= 1;
+                return Some(item);
+            }
+        }
+
+        None
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -474,7 +1063,14 @@ impl<'a, T> Iterator for Iter<'a, T> {
 
 impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
     fn next_back(&mut self) -> Option<&'a T> {
-        self.next()
+        while let Some(slot) = self.slots.next_back() {
+            if let NodeSlot::Full { item, .. } = slot {
+                self.len -= 1;
+                return Some(item);
+            }
+        }
+
+        None
     }
 }
 
@@ -209,3 +209,170 @@ fn append() {
 
     quickcheck(t as fn(_, _) -> _);
 }
+
+#[test]
+fn peek_mut_agrees_with_pop_then_push() {
+    fn t(ops: Vec<Op<i32>>, replacement: i32) -> bool {
+        let mut peeked = SkewHeap::new();
+        let mut popped = SkewHeap::new();
+
+        for op in ops {
+            op.clone().exec(&mut peeked);
+            op.exec(&mut popped);
+        }
+
+        match peeked.peek_mut() {
+            Some(mut top) => *top = replacement,
+            None => return popped.is_empty(),
+        }
+
+        popped.pop();
+        popped.push(replacement);
+
+        let mut peeked: Vec<_> = peeked.into_iter().collect();
+        peeked.sort();
+
+        let mut popped: Vec<_> = popped.into_iter().collect();
+        popped.sort();
+
+        peeked == popped
+    }
+
+    quickcheck(t as fn(_, _) -> _);
+}
+
+#[test]
+fn with_capacity_agrees_with_binary_heap() {
+    fn t(capacity: usize, ops: Vec<Op<i32>>) -> Result<(), Disagreement<i32>> {
+        let capacity = capacity % 64;
+        let mut skew = SkewHeap::with_capacity(capacity);
+        let mut bin = BinaryHeap::with_capacity(capacity);
+
+        for op in ops {
+            let skew_r = op.clone().exec(&mut skew);
+            let bin_r = op.exec_binary(&mut bin);
+
+            if skew_r != bin_r {
+                return Err(Disagreement::Result(skew_r, bin_r));
+            }
+
+            if skew.peek() != bin.peek() {
+                return Err(Disagreement::Peek(skew.peek().cloned(), bin.peek().cloned()));
+            }
+        }
+
+        skew.shrink_to_fit();
+
+        loop {
+            match (skew.pop(), bin.pop()) {
+                (Some(skew), Some(bin)) if skew == bin => {}
+                (None, None) => return Ok(()),
+                (skew, bin) => return Err(Disagreement::Pop(skew, bin)),
+            }
+        }
+    }
+
+    quickcheck(t as fn(_, _) -> _);
+}
+
+#[test]
+fn remove_by_handle() {
+    fn t(values: Vec<i32>, index: usize) -> bool {
+        if values.is_empty() {
+            return true;
+        }
+
+        let mut heap = SkewHeap::new();
+        let handles: Vec<_> = values.iter().map(|&v| heap.push(v)).collect();
+
+        let i = index % values.len();
+
+        if heap.remove(handles[i]) != values[i] {
+            return false;
+        }
+
+        let mut expected = values.clone();
+        expected.remove(i);
+        expected.sort();
+
+        let mut actual: Vec<_> = heap.into_iter().collect();
+        actual.sort();
+
+        actual == expected
+    }
+
+    quickcheck(t as fn(_, _) -> _);
+}
+
+#[test]
+fn into_sorted_vec_agrees_with_binary_heap() {
+    fn t(ops: Vec<Op<i32>>) -> bool {
+        let mut skew = SkewHeap::new();
+        let mut bin = BinaryHeap::new();
+
+        for op in ops {
+            op.clone().exec(&mut skew);
+            op.exec_binary(&mut bin);
+        }
+
+        skew.into_sorted_vec() == bin.into_sorted_vec()
+    }
+
+    quickcheck(t as fn(_) -> _);
+}
+
+#[test]
+fn drain_sorted_agrees_with_pop() {
+    fn t(ops: Vec<Op<i32>>, take: usize) -> bool {
+        let mut skew = SkewHeap::new();
+
+        for op in ops {
+            op.exec(&mut skew);
+        }
+
+        let mut popped = Vec::new();
+
+        while let Some(item) = skew.pop() {
+            popped.push(item);
+        }
+
+        for &item in &popped {
+            skew.push(item);
+        }
+
+        let take = if popped.is_empty() { 0 } else { take % (popped.len() + 1) };
+        let drained: Vec<_> = skew.drain_sorted().take(take).collect();
+
+        drained == &popped[..take] && skew.is_empty()
+    }
+
+    quickcheck(t as fn(_, _) -> _);
+}
+
+#[test]
+fn change_key_reorders() {
+    fn t(values: Vec<i32>, index: usize, new_value: i32) -> bool {
+        if values.is_empty() {
+            return true;
+        }
+
+        let mut heap = SkewHeap::new();
+        let handles: Vec<_> = values.iter().map(|&v| heap.push(v)).collect();
+
+        let i = index % values.len();
+
+        if heap.change_key(handles[i], new_value) != values[i] {
+            return false;
+        }
+
+        let mut expected = values.clone();
+        expected[i] = new_value;
+        expected.sort();
+
+        let actual = heap.into_sorted_vec();
+
+        actual == expected
+    }
+
+    quickcheck(t as fn(_, _, _) -> _);
+}